@@ -0,0 +1,199 @@
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// @notice Name of the per-project configuration file, read from the project
+/// root with a user-level fallback in the home directory.
+const CONFIG_FILE: &str = "coprocessor.toml";
+
+/// @notice Persistent defaults for the flags that Publish and Deploy would
+/// otherwise require on every invocation. Every field is optional so a partial
+/// file (or none at all) is valid; CLI flags override file values and file
+/// values override the built-in defaults.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CoprocessorConfig {
+    /// Name of the default network profile to apply when `--network` is omitted.
+    pub default_network: Option<String>,
+
+    /// Default solver environment (dev/test/prod).
+    pub environment: Option<String>,
+
+    /// Default custom solver URL.
+    pub solver_url: Option<String>,
+
+    /// Default RPC endpoint for deployments.
+    pub rpc: Option<String>,
+
+    /// Default private key for deployments.
+    pub private_key: Option<String>,
+
+    /// Named network profiles, keyed by network name.
+    #[serde(default)]
+    pub networks: HashMap<String, NetworkProfile>,
+
+    /// Programs published together when Publish runs in parallel mode.
+    #[serde(default)]
+    pub programs: Vec<ProgramConfig>,
+
+    /// Completion notifier (webhook/email) fired when a publish/deploy finishes.
+    #[serde(default)]
+    pub notifier: Option<NotifierConfig>,
+}
+
+/// @notice Pluggable notifier fired on completion of a publish/deploy. The
+/// target is read from the project config so CI pipelines and teams can be told
+/// when a long-running upload finishes.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NotifierConfig {
+    /// Webhook URL to POST a JSON summary to.
+    pub webhook_url: Option<String>,
+    /// Email address to notify. The CLI prints this address on completion; no
+    /// mail is sent.
+    pub email: Option<String>,
+}
+
+/// @notice A program entry for parallel multi-program publishing. Each program
+/// is built and uploaded in its own task with isolated working state.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ProgramConfig {
+    /// Human-readable name used in the aggregated report.
+    pub name: String,
+    /// Directory containing the program's sources, relative to the project root.
+    pub path: String,
+    /// Optional per-program solver URL override.
+    pub solver_url: Option<String>,
+}
+
+/// @notice A named network profile bundling the solver/RPC/key overrides that
+/// belong together for a given network.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NetworkProfile {
+    pub solver_url: Option<String>,
+    pub rpc: Option<String>,
+    pub private_key: Option<String>,
+    pub environment: Option<String>,
+}
+
+impl CoprocessorConfig {
+    /// @notice Load the resolved configuration: the project-root file layered on
+    /// top of the user-level fallback. Missing files are treated as empty.
+    pub fn load() -> CoprocessorConfig {
+        let user = Self::read_from(user_config_path());
+        let project = Self::read_from(Some(PathBuf::from(CONFIG_FILE)));
+        user.merged_with(project)
+    }
+
+    /// @notice Read and parse a config file, returning an empty config when the
+    /// path is absent or unreadable.
+    fn read_from(path: Option<PathBuf>) -> CoprocessorConfig {
+        let Some(path) = path else {
+            return CoprocessorConfig::default();
+        };
+        match fs::read_to_string(&path) {
+            Ok(contents) => match toml::from_str(&contents) {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!(
+                        "{} {:?}: {}",
+                        "⚠️  Ignoring malformed config".yellow(),
+                        path,
+                        e
+                    );
+                    CoprocessorConfig::default()
+                }
+            },
+            Err(_) => CoprocessorConfig::default(),
+        }
+    }
+
+    /// @notice Overlay `other` (higher precedence) onto `self`, field by field.
+    fn merged_with(mut self, other: CoprocessorConfig) -> CoprocessorConfig {
+        self.default_network = other.default_network.or(self.default_network);
+        self.environment = other.environment.or(self.environment);
+        self.solver_url = other.solver_url.or(self.solver_url);
+        self.rpc = other.rpc.or(self.rpc);
+        self.private_key = other.private_key.or(self.private_key);
+        self.networks.extend(other.networks);
+        self.notifier = other.notifier.or(self.notifier);
+        // Programs are normally declared in the project-root file, so let a
+        // non-empty higher-precedence list replace the fallback rather than
+        // silently dropping it.
+        if !other.programs.is_empty() {
+            self.programs = other.programs;
+        }
+        self
+    }
+
+    /// @notice Resolve the network name to use, preferring an explicit CLI value
+    /// and falling back to the default profile declared in the config.
+    pub fn resolve_network(&self, cli: Option<String>) -> Option<String> {
+        cli.or_else(|| self.default_network.clone())
+    }
+
+    /// @notice Look up a named profile so callers can fill in solver/RPC/key
+    /// defaults for the resolved network.
+    pub fn profile(&self, network: &str) -> Option<&NetworkProfile> {
+        self.networks.get(network)
+    }
+}
+
+/// @notice Path to the user-level fallback config (`~/.config/coprocessor.toml`).
+fn user_config_path() -> Option<PathBuf> {
+    env::var("HOME")
+        .ok()
+        .map(|home| PathBuf::from(home).join(".config").join(CONFIG_FILE))
+}
+
+/// @notice Function backing the `Config` subcommand: initialize a template
+/// config in the project root when one is missing, then print the resolved
+/// configuration.
+/// @param init When true, write a starter `coprocessor.toml` if absent
+pub fn config(init: bool) {
+    if init {
+        let path = PathBuf::from(CONFIG_FILE);
+        if path.exists() {
+            println!("{} {}", "coprocessor.toml already exists at".yellow(), CONFIG_FILE);
+        } else {
+            match fs::write(&path, STARTER_CONFIG) {
+                Ok(()) => println!("✅ {} {}", "Created".green(), CONFIG_FILE),
+                Err(e) => {
+                    eprintln!("{} {}", "❌ Failed to write coprocessor.toml:".red(), e);
+                    return;
+                }
+            }
+        }
+    }
+
+    let resolved = CoprocessorConfig::load();
+    match toml::to_string_pretty(&resolved) {
+        Ok(rendered) => {
+            println!("{}", "Resolved configuration:".green());
+            println!("{}", rendered);
+        }
+        Err(e) => eprintln!("{} {}", "❌ Failed to render configuration:".red(), e),
+    }
+}
+
+/// @notice Commented starter file written by `config --init`.
+const STARTER_CONFIG: &str = r#"# Persistent defaults for the coprocessor CLI.
+# CLI flags override these values.
+
+default_network = "devnet"
+environment = "prod"
+# solver_url = "https://solver.example.com"
+
+[networks.devnet]
+rpc = "http://localhost:8545"
+# private_key = "0x..."
+
+[networks.mainnet]
+# rpc = "https://mainnet.example.com"
+# private_key = "0x..."
+
+# [notifier]
+# webhook_url = "https://hooks.example.com/coprocessor"
+# email = "team@example.com"
+"#;
@@ -0,0 +1,127 @@
+use colored::Colorize;
+use include_dir::{include_dir, Dir};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// @notice Embedded Cartesi + Solidity template shipped with the CLI. Files are
+/// either rendered (placeholders substituted) or copied verbatim according to
+/// the template manifest.
+static TEMPLATE: Dir = include_dir!("$CARGO_MANIFEST_DIR/templates");
+
+/// @notice Name of the manifest inside a template listing which files should be
+/// rendered through the substitution engine rather than copied byte-for-byte.
+const MANIFEST: &str = "template.manifest";
+
+/// @notice Function to bootstrap a new coprocessor program directory from the
+/// embedded template, personalising it with the supplied values.
+/// @param dapp_name Name of the program, used for the directory and placeholders
+/// @param template Language/template the user intends to build with
+pub fn create(dapp_name: String, template: String) {
+    let target = Path::new(&dapp_name);
+    if target.exists() {
+        eprintln!("{} {}", "❌ Directory already exists:".red(), dapp_name);
+        return;
+    }
+
+    // Placeholders available to rendered template files. Mirrors the existing
+    // {{ image }}/{{ pkg }} substitution pattern used elsewhere in the template.
+    let author = env::var("USER").unwrap_or_else(|_| "anonymous".to_string());
+    let mut vars = HashMap::new();
+    vars.insert("dapp_name", dapp_name.clone());
+    vars.insert("template", template.clone());
+    vars.insert("author", author);
+
+    let render_set = manifest_entries();
+
+    if let Err(e) = write_dir(&TEMPLATE, target, &vars, &render_set) {
+        eprintln!("{} {}", "❌ Failed to scaffold project:".red(), e);
+        return;
+    }
+
+    println!(
+        "✅ {} {}",
+        "Created new coprocessor program at".green(),
+        dapp_name.green()
+    );
+}
+
+/// @notice Parse the template manifest into the set of paths (relative to the
+/// template root) whose contents should be rendered. A missing manifest means
+/// nothing is rendered and every file is copied verbatim.
+fn manifest_entries() -> Vec<String> {
+    TEMPLATE
+        .get_file(MANIFEST)
+        .and_then(|f| f.contents_utf8())
+        .map(|contents| {
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(|line| line.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// @notice Recursively write an embedded directory to disk, rendering the files
+/// named in the manifest and copying the rest verbatim. File and directory
+/// names are rendered for every entry, whether or not it is in the manifest.
+/// @param dir Embedded directory currently being written
+/// @param dest Filesystem destination for this directory
+/// @param vars Placeholder values available to rendered files
+/// @param render_set Relative paths that should be rendered rather than copied
+fn write_dir(
+    dir: &Dir,
+    dest: &Path,
+    vars: &HashMap<&str, String>,
+    render_set: &[String],
+) -> std::io::Result<()> {
+    fs::create_dir_all(dest)?;
+
+    for file in dir.files() {
+        let rel = file.path();
+        // The manifest itself never lands in the scaffolded project.
+        if rel.to_string_lossy() == MANIFEST {
+            continue;
+        }
+
+        let name = rel
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let out = dest.join(render(&name, vars));
+
+        if render_set.iter().any(|p| p == &rel.to_string_lossy()) {
+            let contents = file.contents_utf8().unwrap_or_default();
+            fs::write(out, render(contents, vars))?;
+        } else {
+            fs::write(out, file.contents())?;
+        }
+    }
+
+    for sub in dir.dirs() {
+        let name = sub
+            .path()
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        write_dir(sub, &dest.join(render(&name, vars)), vars, render_set)?;
+    }
+
+    Ok(())
+}
+
+/// @notice Substitute `{{ key }}` placeholders in `input` with the matching
+/// values, leaving unknown placeholders untouched. Whitespace inside the braces
+/// is tolerated so both `{{key}}` and `{{ key }}` resolve.
+fn render(input: &str, vars: &HashMap<&str, String>) -> String {
+    let mut rendered = input.to_string();
+    for (key, value) in vars {
+        for pattern in [format!("{{{{ {} }}}}", key), format!("{{{{{}}}}}", key)] {
+            rendered = rendered.replace(&pattern, value);
+        }
+    }
+    rendered
+}
@@ -0,0 +1,224 @@
+use crate::commands::dbctx::DbCtx;
+use crate::commands::devnet::{reset_devnet, start_devnet, stop_devnet};
+use crate::commands::publish::build_cartesi_machine_and_generate_car;
+use bollard::container::{ListContainersOptions, LogsOptions};
+use bollard::Docker;
+use colored::Colorize;
+use crossterm::event::{self, Event, KeyCode};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::execute;
+use futures_util::stream::StreamExt;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Terminal;
+use std::collections::VecDeque;
+use std::io;
+use std::time::Duration;
+
+/// @notice Project prefix shared with the devnet module so the dashboard lists
+/// the same containers the `StartDevnet` command brings up.
+const PROJECT: &str = "cartesi-coprocessor-devnet";
+
+/// @notice Snapshot of devnet/solver state rendered each frame.
+#[derive(Default)]
+struct DashboardState {
+    /// `name -> status` for every devnet container reported by the Engine API.
+    containers: Vec<(String, String)>,
+    /// Most recent container log lines, oldest first.
+    logs: VecDeque<String>,
+    /// Current machine hash, if known.
+    machine_hash: Option<String>,
+    /// Current CAR CID, if known.
+    car_cid: Option<String>,
+    /// Latest solver reachability (`reachable`/`unreachable` from `/health`);
+    /// download/registration status is not polled.
+    solver_status: String,
+}
+
+/// @notice Function backing the `Dashboard` subcommand: open a terminal UI that
+/// continuously shows devnet container health, streaming logs, the machine
+/// hash/CAR CID and solver status, with keybindings to drive the devnet and
+/// trigger a rebuild without leaving the view.
+/// @param solver_url Solver base URL whose `/health` endpoint is polled for reachability
+pub fn dashboard(solver_url: Option<String>) {
+    if let Err(e) = run(solver_url) {
+        eprintln!("{} {}", "❌ Dashboard exited with error:".red(), e);
+    }
+}
+
+/// @notice Set up the terminal, run the event loop, and restore the terminal on
+/// exit regardless of how the loop ends.
+fn run(solver_url: Option<String>) -> io::Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let result = event_loop(&mut terminal, solver_url);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    result
+}
+
+/// @notice The continuously-updating operator console: redraw, poll state, and
+/// handle keybindings until the user quits.
+fn event_loop<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    solver_url: Option<String>,
+) -> io::Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    let docker = Docker::connect_with_local_defaults().ok();
+    let mut state = DashboardState::default();
+
+    loop {
+        if let Some(docker) = docker.as_ref() {
+            runtime.block_on(refresh(docker, &mut state, solver_url.as_deref()));
+        }
+
+        terminal.draw(|frame| draw(frame, &state))?;
+
+        // Keybindings: q quit, s start, x stop, r reset, b rebuild.
+        if event::poll(Duration::from_millis(500))? {
+            if let Event::Key(key) = event::read()? {
+                match key.code {
+                    KeyCode::Char('q') => break,
+                    KeyCode::Char('s') => start_devnet(),
+                    KeyCode::Char('x') => stop_devnet(),
+                    KeyCode::Char('r') => reset_devnet(),
+                    KeyCode::Char('b') => {
+                        if let Some(artifacts) = build_cartesi_machine_and_generate_car() {
+                            state.machine_hash = Some(artifacts.machine_hash);
+                            state.car_cid = Some(artifacts.car_cid);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// @notice Pull fresh container health, recent logs and solver status from the
+/// Docker and solver APIs into the dashboard state.
+async fn refresh(docker: &Docker, state: &mut DashboardState, solver_url: Option<&str>) {
+    let mut filters = std::collections::HashMap::new();
+    filters.insert("name".to_string(), vec![PROJECT.to_string()]);
+
+    let options = ListContainersOptions {
+        all: true,
+        filters,
+        ..Default::default()
+    };
+
+    if let Ok(containers) = docker.list_containers(Some(options)).await {
+        state.containers = containers
+            .into_iter()
+            .map(|c| {
+                let name = c
+                    .names
+                    .and_then(|n| n.into_iter().next())
+                    .unwrap_or_default();
+                let status = c.status.unwrap_or_default();
+                (name.trim_start_matches('/').to_string(), status)
+            })
+            .collect();
+
+        // Tail the first container's last 20 log lines into the log pane; the
+        // pane does not scroll.
+        if let Some((name, _)) = state.containers.first() {
+            let mut logs = docker.logs(
+                name,
+                Some(LogsOptions::<String> {
+                    stdout: true,
+                    stderr: true,
+                    tail: "20".to_string(),
+                    ..Default::default()
+                }),
+            );
+            state.logs.clear();
+            while let Some(Ok(chunk)) = logs.next().await {
+                state.logs.push_back(chunk.to_string().trim_end().to_string());
+                if state.logs.len() > 200 {
+                    state.logs.pop_front();
+                }
+            }
+        }
+    }
+
+    // Surface the machine hash / CAR CID from the most recent recorded run that
+    // produced them, unless a rebuild in this session already set fresher values.
+    if state.machine_hash.is_none() || state.car_cid.is_none() {
+        if let Ok(db) = DbCtx::open() {
+            if let Ok(runs) = db.list(None, None) {
+                if let Some(run) = runs
+                    .iter()
+                    .find(|r| r.machine_hash.is_some() || r.car_cid.is_some())
+                {
+                    state.machine_hash = run.machine_hash.clone();
+                    state.car_cid = run.car_cid.clone();
+                }
+            }
+        }
+    }
+
+    if let Some(url) = solver_url {
+        state.solver_status = match ureq::get(&format!("{}/health", url.trim_end_matches('/'))).call() {
+            Ok(_) => "reachable".to_string(),
+            Err(_) => "unreachable".to_string(),
+        };
+    }
+}
+
+/// @notice Lay out and render a single frame: header, container health, log
+/// pane and a keybinding footer.
+fn draw(frame: &mut ratatui::Frame, state: &DashboardState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),
+            Constraint::Min(5),
+            Constraint::Length(3),
+        ])
+        .split(frame.size());
+
+    let header = Paragraph::new(vec![
+        Line::from(format!(
+            "machine hash: {}",
+            state.machine_hash.as_deref().unwrap_or("-")
+        )),
+        Line::from(format!("CAR CID: {}", state.car_cid.as_deref().unwrap_or("-"))),
+        Line::from(format!("solver: {}", state.solver_status)),
+    ])
+    .block(Block::default().borders(Borders::ALL).title("Coprocessor"));
+    frame.render_widget(header, chunks[0]);
+
+    let mut lines: Vec<Line> = state
+        .containers
+        .iter()
+        .map(|(name, status)| {
+            let color = if status.contains("healthy") || status.contains("Up") {
+                Color::Green
+            } else {
+                Color::Red
+            };
+            Line::styled(format!("{:30} {}", name, status), Style::default().fg(color))
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.extend(state.logs.iter().map(|l| Line::from(l.clone())));
+    let body = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("Devnet"));
+    frame.render_widget(body, chunks[1]);
+
+    let footer = Paragraph::new("[s]tart  [x] stop  [r]eset  [b]uild  [q]uit")
+        .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(footer, chunks[2]);
+}
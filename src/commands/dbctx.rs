@@ -0,0 +1,298 @@
+use crate::commands::config::{CoprocessorConfig, NotifierConfig};
+use colored::Colorize;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// @notice Kinds of run recorded in the local history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RunKind {
+    Build,
+    Publish,
+    Deploy,
+}
+
+impl RunKind {
+    /// @notice Stable string used as the column value in SQLite.
+    fn as_str(&self) -> &'static str {
+        match self {
+            RunKind::Build => "build",
+            RunKind::Publish => "publish",
+            RunKind::Deploy => "deploy",
+        }
+    }
+
+    /// @notice Parse a `History --kind` filter value.
+    pub fn parse(value: &str) -> Option<RunKind> {
+        match value.to_lowercase().as_str() {
+            "build" => Some(RunKind::Build),
+            "publish" => Some(RunKind::Publish),
+            "deploy" => Some(RunKind::Deploy),
+            _ => None,
+        }
+    }
+}
+
+/// @notice Artifacts produced by a successful Cartesi machine build / publish:
+/// the machine hash and the CAR CID. Threaded out of the build and registration
+/// steps so a run can be recorded with real values (and `None` signals failure).
+#[derive(Debug, Clone)]
+pub struct BuildArtifacts {
+    pub machine_hash: String,
+    pub car_cid: String,
+}
+
+/// @notice A single recorded Build/Publish/Deploy run.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub kind: RunKind,
+    pub machine_hash: Option<String>,
+    pub car_cid: Option<String>,
+    pub network: Option<String>,
+    pub solver_url: Option<String>,
+    pub contract_address: Option<String>,
+    /// Unix timestamp (seconds) the run completed.
+    pub timestamp: i64,
+    pub success: bool,
+}
+
+/// @notice Handle to the local history database.
+pub struct DbCtx {
+    conn: Connection,
+}
+
+impl DbCtx {
+    /// @notice Open (creating if needed) the history database under
+    /// `~/.cartesi-coprocessor-repo/history.db` and ensure the schema exists.
+    pub fn open() -> rusqlite::Result<DbCtx> {
+        let conn = Connection::open(db_path())?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                kind TEXT NOT NULL,
+                machine_hash TEXT,
+                car_cid TEXT,
+                network TEXT,
+                solver_url TEXT,
+                contract_address TEXT,
+                timestamp INTEGER NOT NULL,
+                success INTEGER NOT NULL
+            )",
+            [],
+        )?;
+        Ok(DbCtx { conn })
+    }
+
+    /// @notice Persist a completed run.
+    pub fn record(&self, run: &Run) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT INTO runs
+                (kind, machine_hash, car_cid, network, solver_url, contract_address, timestamp, success)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                run.kind.as_str(),
+                run.machine_hash,
+                run.car_cid,
+                run.network,
+                run.solver_url,
+                run.contract_address,
+                run.timestamp,
+                run.success as i64,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// @notice Load recorded runs most-recent first, optionally filtered by kind
+    /// and/or network.
+    pub fn list(&self, kind: Option<RunKind>, network: Option<&str>) -> rusqlite::Result<Vec<Run>> {
+        let mut sql = String::from(
+            "SELECT kind, machine_hash, car_cid, network, solver_url, contract_address, timestamp, success FROM runs",
+        );
+        // Build the filter clauses with bound parameters rather than
+        // string-formatted values, mirroring `record`'s use of `params!`.
+        let mut clauses = Vec::new();
+        let mut values: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        if let Some(kind) = kind {
+            values.push(Box::new(kind.as_str().to_string()));
+            clauses.push(format!("kind = ?{}", values.len()));
+        }
+        if let Some(network) = network {
+            values.push(Box::new(network.to_string()));
+            clauses.push(format!("network = ?{}", values.len()));
+        }
+        if !clauses.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&clauses.join(" AND "));
+        }
+        sql.push_str(" ORDER BY timestamp DESC");
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())), |row| {
+            let kind: String = row.get(0)?;
+            Ok(Run {
+                kind: RunKind::parse(&kind).unwrap_or(RunKind::Build),
+                machine_hash: row.get(1)?,
+                car_cid: row.get(2)?,
+                network: row.get(3)?,
+                solver_url: row.get(4)?,
+                contract_address: row.get(5)?,
+                timestamp: row.get(6)?,
+                success: row.get::<_, i64>(7)? != 0,
+            })
+        })?;
+        rows.collect()
+    }
+}
+
+/// @notice Current Unix timestamp in seconds, used to stamp a completed run.
+pub fn now_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// @notice Record a completed run in the local history and fire the configured
+/// notifier. Best-effort: database errors are logged but never abort the
+/// command, so history/notifications can't break a successful publish.
+/// @param run The completed run to persist and report
+pub fn record_and_notify(run: Run) {
+    match DbCtx::open() {
+        Ok(db) => {
+            if let Err(e) = db.record(&run) {
+                eprintln!("{} {}", "⚠️  Failed to record run history:".yellow(), e);
+            }
+        }
+        Err(e) => eprintln!("{} {}", "⚠️  Failed to open history database:".yellow(), e),
+    }
+    notify_completion(&run);
+}
+
+/// @notice Reconcile locally recorded publishes for `network` against the live
+/// solver: every recorded CAR CID is checked against the solver so a stale local
+/// history is visible. Best-effort — an unreachable solver is reported, not
+/// fatal. Called alongside `check_network_and_confirm_status`.
+/// @param network Target network to reconcile
+/// @param solver_url Fallback solver URL when a run recorded none
+pub fn reconcile_recorded_publishes(network: &str, solver_url: Option<&str>) {
+    let db = match DbCtx::open() {
+        Ok(db) => db,
+        Err(_) => return,
+    };
+    let runs = match db.list(Some(RunKind::Publish), Some(network)) {
+        Ok(runs) => runs,
+        Err(_) => return,
+    };
+    if runs.is_empty() {
+        return;
+    }
+
+    println!("{}", "Reconciling recorded publishes with solver status:".green());
+    for run in runs {
+        let Some(cid) = run.car_cid.as_deref() else {
+            continue;
+        };
+        match run.solver_url.as_deref().or(solver_url) {
+            Some(base) => {
+                let url = format!("{}/status/{}", base.trim_end_matches('/'), cid);
+                match ureq::get(&url).call() {
+                    Ok(_) => println!("  ✅ {} confirmed on solver", cid),
+                    Err(_) => println!("  {} {} not confirmed by solver", "⚠️ ".yellow(), cid),
+                }
+            }
+            None => println!("  • {} (no solver URL to check against)", cid),
+        }
+    }
+}
+
+/// @notice Path to the history database, co-located with the devnet repo clone.
+fn db_path() -> PathBuf {
+    let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(home)
+        .join(".cartesi-coprocessor-repo")
+        .join("history.db")
+}
+
+/// @notice Function backing the `History` subcommand: list and filter past runs.
+/// @param kind Optional kind filter (build/publish/deploy)
+/// @param network Optional network filter
+pub fn history(kind: Option<String>, network: Option<String>) {
+    let kind = match kind.as_deref().map(RunKind::parse) {
+        Some(Some(kind)) => Some(kind),
+        Some(None) => {
+            eprintln!("{}", "❌ Unknown kind filter, expected build/publish/deploy".red());
+            return;
+        }
+        None => None,
+    };
+
+    let db = match DbCtx::open() {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("{} {}", "❌ Failed to open history database:".red(), e);
+            return;
+        }
+    };
+
+    match db.list(kind, network.as_deref()) {
+        Ok(runs) if runs.is_empty() => println!("No recorded runs yet."),
+        Ok(runs) => {
+            for run in runs {
+                let status = if run.success {
+                    "ok".green()
+                } else {
+                    "failed".red()
+                };
+                println!(
+                    "[{}] {:7} {} network={} cid={} addr={}",
+                    run.timestamp,
+                    run.kind.as_str(),
+                    status,
+                    run.network.as_deref().unwrap_or("-"),
+                    run.car_cid.as_deref().unwrap_or("-"),
+                    run.contract_address.as_deref().unwrap_or("-"),
+                );
+            }
+        }
+        Err(e) => eprintln!("{} {}", "❌ Failed to read history:".red(), e),
+    }
+}
+
+/// @notice Send a completion notification for a run, if a notifier is configured.
+/// Best-effort: failures are logged but never abort the command.
+/// @param run The completed run to report
+pub fn notify_completion(run: &Run) {
+    let cfg = CoprocessorConfig::load();
+    let notifier = notifier_from_config(&cfg);
+
+    if let Some(url) = notifier.webhook_url {
+        let body = serde_json::json!({
+            "kind": run.kind,
+            "network": run.network,
+            "car_cid": run.car_cid,
+            "contract_address": run.contract_address,
+            "success": run.success,
+            "timestamp": run.timestamp,
+        });
+        match ureq::post(&url).send_json(body) {
+            Ok(_) => println!("📣 {}", "Webhook notification sent.".green()),
+            Err(e) => eprintln!("{} {}", "⚠️  Failed to POST webhook:".yellow(), e),
+        }
+    }
+
+    if let Some(email) = notifier.email {
+        // No mail is sent: the configured address is only printed, so the
+        // behaviour is observable in logs.
+        println!("📣 Completion notification for {} (email is not sent)", email);
+    }
+}
+
+/// @notice Read the notifier section out of the resolved config. Kept separate
+/// so the config schema stays the single source of truth.
+fn notifier_from_config(cfg: &CoprocessorConfig) -> NotifierConfig {
+    // The notifier block is optional; an absent one yields no notifications.
+    cfg.notifier.clone().unwrap_or_default()
+}
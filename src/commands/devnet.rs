@@ -1,59 +1,191 @@
 use crate::helpers::helpers::get_spinner;
+use bollard::container::{
+    Config, CreateContainerOptions, RemoveContainerOptions, StartContainerOptions,
+    StopContainerOptions,
+};
+use bollard::image::{BuildImageOptions, CreateImageOptions};
+use bollard::models::{ContainerStateStatusEnum, HealthStatusEnum, HostConfig, PortBinding};
+use bollard::network::CreateNetworkOptions;
+use bollard::Docker;
 use colored::Colorize;
+use futures_util::stream::StreamExt;
+use std::collections::HashMap;
 use std::env;
 use std::fs;
-use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::{thread, time};
 
+/// @notice Compose project name reused for every container so they can be
+/// grouped, inspected and torn down together without the compose CLI.
+const PROJECT: &str = "cartesi-coprocessor-devnet";
+
+/// @notice A single devnet service modelled from `docker-compose-devnet.yaml`.
+/// Each entry is turned into a container create/start/stop/remove call against
+/// the Docker Engine API instead of being handed to `docker compose`.
+struct Service {
+    /// Service name as it appears in the compose file.
+    name: &'static str,
+    /// Image to pull when the service has no build context.
+    image: &'static str,
+    /// Build context (relative to the repo root) when the image is built locally.
+    build_context: Option<&'static str>,
+    /// Dockerfile within the build context.
+    dockerfile: &'static str,
+    /// Container entrypoint arguments (`command:` in the compose file).
+    command: Option<&'static [&'static str]>,
+    /// Environment variables as `KEY=VALUE` (`environment:` in the compose file).
+    env: &'static [&'static str],
+    /// Published ports as `(host, container)` pairs (`ports:` in the compose file).
+    ports: &'static [(&'static str, &'static str)],
+    /// Bind/volume mounts as `source:target` specs (`volumes:` in the compose file).
+    volumes: &'static [&'static str],
+    /// Service names that must be running first (`depends_on:` in the compose file).
+    depends_on: &'static [&'static str],
+}
+
+/// @notice The devnet services, in dependency order, mirroring
+/// `docker-compose-devnet.yaml`. Bringing the stack up walks this list
+/// front-to-back; tearing it down walks it back-to-front.
+const SERVICES: &[Service] = &[
+    Service {
+        name: "anvil",
+        image: "ghcr.io/foundry-rs/foundry:latest",
+        build_context: None,
+        dockerfile: "Dockerfile",
+        command: Some(&["anvil", "--host", "0.0.0.0", "--block-time", "1"]),
+        env: &[],
+        ports: &[("8545", "8545")],
+        volumes: &[],
+        depends_on: &[],
+    },
+    Service {
+        name: "coprocessor-operator",
+        image: "ghcr.io/zippiehq/cartesi-coprocessor-operator:latest",
+        build_context: Some("operator"),
+        dockerfile: "Dockerfile",
+        command: None,
+        env: &["RUST_LOG=info", "ANVIL_HTTP_ENDPOINT=http://anvil:8545"],
+        ports: &[("3033", "3033")],
+        volumes: &[],
+        depends_on: &["anvil"],
+    },
+    Service {
+        name: "coprocessor-solver",
+        image: "ghcr.io/zippiehq/cartesi-coprocessor-solver:latest",
+        build_context: Some("solver"),
+        dockerfile: "Dockerfile",
+        command: None,
+        env: &[
+            "RUST_LOG=info",
+            "ANVIL_HTTP_ENDPOINT=http://anvil:8545",
+            "OPERATOR_ENDPOINT=http://coprocessor-operator:3033",
+        ],
+        ports: &[("3034", "3034")],
+        volumes: &[],
+        depends_on: &["anvil", "coprocessor-operator"],
+    },
+];
+
+/// @notice Build a tokio runtime and connect to the local Docker daemon.
+/// Returns `None` (after printing a diagnostic) when the daemon is unreachable,
+/// so callers can bail out the same way the old shell-outs did.
+fn docker_client() -> Option<(tokio::runtime::Runtime, Docker)> {
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("❌ Failed to create async runtime: {:?}", e);
+            return None;
+        }
+    };
+
+    match Docker::connect_with_local_defaults() {
+        Ok(docker) => Some((runtime, docker)),
+        Err(e) => {
+            eprintln!(
+                "{} {}",
+                "❌ Failed to connect to the Docker Engine API:".red(),
+                format!("{:?}", e).red()
+            );
+            None
+        }
+    }
+}
+
 /// @notice Function to start a local development network set of docker containers for Cartesi-Coprocessor
 pub fn start_devnet() {
     let coprocessor_path = clone_coprocessor_repo();
-    match coprocessor_path {
-        Some(path) => {
-            build_container(path.clone());
-            pull_container(path.clone());
-            let spinner = get_spinner();
-            spinner.set_message("Starting devnet containers...");
-
-            // Run Cartesi-Coprocessor in the background
-            let docker_status = Command::new("docker")
-                .arg("compose")
-                .arg("-f")
-                .arg("docker-compose-devnet.yaml")
-                .arg("up")
-                .arg("--wait")
-                .arg("-d")
-                .current_dir(path)
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
-                .expect("Failed to start Cartesi-Coprocessor devnet environment")
-                .wait_with_output()
-                .expect("Failed to complete git status check");
-
-            if docker_status.status.success() {
+    let path = match coprocessor_path {
+        Some(path) => path,
+        None => {
+            eprintln!("❌ Failed to clone Cartesi-Coprocessor repository.");
+            return;
+        }
+    };
+
+    let (runtime, docker) = match docker_client() {
+        Some(client) => client,
+        None => return,
+    };
+
+    runtime.block_on(async {
+        // A failed build or pull leaves the stack without usable images, so bail
+        // out instead of falling through to container creation.
+        if !build_container(&docker, &path).await {
+            return;
+        }
+        if !pull_container(&docker, &path).await {
+            return;
+        }
+
+        let spinner = get_spinner();
+        spinner.set_message("Starting devnet containers...");
+
+        // The containers attach to a dedicated project network, so it has to
+        // exist before any of them is started.
+        if let Err(e) = ensure_network(&docker).await {
+            spinner.finish_and_clear();
+            eprintln!(
+                "{}\n{}",
+                "❌ Failed to create the devnet network.".red(),
+                format!("{:?}", e).red()
+            );
+            return;
+        }
+
+        for service in SERVICES {
+            if let Err(e) = create_and_start(&docker, service).await {
+                spinner.finish_and_clear();
+                eprintln!(
+                    "{} {}\n{}",
+                    "❌ Failed to start devnet container".red(),
+                    service.name.red(),
+                    format!("{:?}", e).red()
+                );
+                return;
+            }
+        }
+
+        // Emulate `--wait`: poll each container's state/health from the API so we
+        // can tell a half-up devnet from a fully healthy one.
+        match wait_for_healthy(&docker).await {
+            Ok(()) => {
                 spinner.finish_and_clear();
                 println!(
                     "✅ {}",
                     "Cartesi-Coprocessor devnet environment started.".green()
-                )
-            } else {
+                );
+            }
+            Err(service) => {
                 spinner.finish_and_clear();
                 eprintln!(
-                    "{} \n{}",
-                    "❌ Failed to start devnet containers:".red(),
-                    String::from_utf8_lossy(&docker_status.stderr).red()
+                    "{} {}",
+                    "❌ Devnet came up only partially, unhealthy container:".red(),
+                    service.red()
                 );
-                return;
             }
         }
-        None => {
-            eprintln!("❌ Failed to clone Cartesi-Coprocessor repository.");
-            return;
-        }
-    }
+    });
 }
 
 /// @notice Function to clone the cartesi-coprocessor repository into a specified repo on host machine
@@ -193,19 +325,20 @@ fn update_submodules(path: String) -> bool {
         .spawn()
         .expect("Failed to execute git submodule update command");
 
-    let stdout = BufReader::new(
+    let stdout = std::io::BufReader::new(
         update_status
             .stdout
             .take()
             .expect("Failed to capture stdout"),
     );
-    let stderr = BufReader::new(
+    let stderr = std::io::BufReader::new(
         update_status
             .stderr
             .take()
             .expect("Failed to capture stderr"),
     );
     // Handle output in separate threads
+    use std::io::BufRead;
     thread::spawn(move || {
         for line in stdout.lines() {
             if let Ok(line) = line {
@@ -247,109 +380,355 @@ fn update_submodules(path: String) -> bool {
 /// @notice Function to Stop a currently running local dev network containers for the coprocessor
 pub fn stop_devnet() {
     let coprocessor_path = clone_coprocessor_repo();
+    if coprocessor_path.is_none() {
+        eprintln!("❌ Failed to clone Cartesi-Coprocessor repository.");
+        return;
+    }
 
-    match coprocessor_path {
-        Some(path) => {
-            let spinner = get_spinner();
-            spinner.set_message("Stoping devnet containers...");
-
-            // Run Cartesi-Coprocessor in the background
-            let docker_status = Command::new("docker")
-                .arg("compose")
-                .arg("-f")
-                .arg("docker-compose-devnet.yaml")
-                .arg("down")
-                .arg("-v")
-                .current_dir(path)
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
-                .expect("Failed to start Cartesi-Coprocessor devnet environment")
-                .wait_with_output()
-                .expect("Failed to complete git status check");
-
-            if docker_status.status.success() {
-                spinner.finish_and_clear();
-                println!(
-                    "✅ {}",
-                    "Cartesi-Coprocessor devnet environment stoped.".green()
-                )
-            } else {
-                spinner.finish_and_clear();
+    let (runtime, docker) = match docker_client() {
+        Some(client) => client,
+        None => return,
+    };
+
+    runtime.block_on(async {
+        let spinner = get_spinner();
+        spinner.set_message("Stoping devnet containers...");
+
+        let mut failed = false;
+        // Tear down in reverse dependency order.
+        for service in SERVICES.iter().rev() {
+            if let Err(e) = stop_and_remove(&docker, service).await {
+                failed = true;
                 eprintln!(
-                    "{} \n{}",
-                    "❌ Failed to stop devnet containers:".red(),
-                    String::from_utf8_lossy(&docker_status.stderr).red()
+                    "{} {}\n{}",
+                    "❌ Failed to stop devnet container".red(),
+                    service.name.red(),
+                    format!("{:?}", e).red()
                 );
+            }
+        }
+
+        spinner.finish_and_clear();
+        if !failed {
+            println!(
+                "✅ {}",
+                "Cartesi-Coprocessor devnet environment stoped.".green()
+            );
+        }
+    });
+}
+
+/// @notice Container name for a service within the devnet project.
+fn container_name(service: &Service) -> String {
+    format!("{}-{}", PROJECT, service.name)
+}
+
+/// @notice Create the project network, treating an existing one (HTTP 409) as
+/// success so `start_devnet` can be re-run safely.
+/// @param docker Connected Docker Engine API client
+async fn ensure_network(docker: &Docker) -> Result<(), bollard::errors::Error> {
+    let options = CreateNetworkOptions {
+        name: PROJECT.to_string(),
+        ..Default::default()
+    };
+    match docker.create_network(options).await {
+        Ok(_) => Ok(()),
+        // The network already exists from a previous start; reuse it.
+        Err(bollard::errors::Error::DockerResponseServerError {
+            status_code: 409, ..
+        }) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// @notice Create (if needed) and start the container backing a service, applying
+/// the full compose configuration (command, environment, ports, volumes) and
+/// honouring `depends_on`. Re-running after a partial start is idempotent: an
+/// existing container (HTTP 409) is reused and an already-running one (HTTP 304)
+/// is left alone.
+/// @param docker Connected Docker Engine API client
+/// @param service The compose service to materialise as a container
+async fn create_and_start(docker: &Docker, service: &Service) -> Result<(), bollard::errors::Error> {
+    let name = container_name(service);
+
+    // Wait for declared dependencies to be up before starting this service.
+    for dep in service.depends_on {
+        wait_for_running(docker, &format!("{}-{}", PROJECT, dep)).await;
+    }
+
+    let mut port_bindings = HashMap::new();
+    let mut exposed_ports = HashMap::new();
+    for (host, container) in service.ports {
+        let key = format!("{}/tcp", container);
+        port_bindings.insert(
+            key.clone(),
+            Some(vec![PortBinding {
+                host_ip: Some("0.0.0.0".to_string()),
+                host_port: Some(host.to_string()),
+            }]),
+        );
+        exposed_ports.insert(key, HashMap::new());
+    }
+
+    let config = Config {
+        image: Some(service.image.to_string()),
+        cmd: service
+            .command
+            .map(|cmd| cmd.iter().map(|arg| arg.to_string()).collect()),
+        env: if service.env.is_empty() {
+            None
+        } else {
+            Some(service.env.iter().map(|var| var.to_string()).collect())
+        },
+        exposed_ports: if exposed_ports.is_empty() {
+            None
+        } else {
+            Some(exposed_ports)
+        },
+        host_config: Some(HostConfig {
+            network_mode: Some(PROJECT.to_string()),
+            port_bindings: if port_bindings.is_empty() {
+                None
+            } else {
+                Some(port_bindings)
+            },
+            binds: if service.volumes.is_empty() {
+                None
+            } else {
+                Some(service.volumes.iter().map(|bind| bind.to_string()).collect())
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    match docker
+        .create_container(
+            Some(CreateContainerOptions {
+                name: name.clone(),
+                platform: None,
+            }),
+            config,
+        )
+        .await
+    {
+        Ok(_) => {}
+        // Container left over from a previous partial start; reuse it.
+        Err(bollard::errors::Error::DockerResponseServerError {
+            status_code: 409, ..
+        }) => {}
+        Err(e) => return Err(e),
+    }
+
+    match docker
+        .start_container(&name, None::<StartContainerOptions<String>>)
+        .await
+    {
+        Ok(()) => Ok(()),
+        // Already running from a previous start; nothing to do.
+        Err(bollard::errors::Error::DockerResponseServerError {
+            status_code: 304, ..
+        }) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// @notice Best-effort wait until a container reports a running state, used to
+/// honour a service's `depends_on` ordering before starting it.
+/// @param docker Connected Docker Engine API client
+/// @param name Full container name to poll
+async fn wait_for_running(docker: &Docker, name: &str) {
+    for _ in 0..30 {
+        if let Ok(details) = docker.inspect_container(name, None).await {
+            if details.state.and_then(|state| state.status)
+                == Some(ContainerStateStatusEnum::RUNNING)
+            {
                 return;
             }
         }
-        None => {
-            eprintln!("❌ Failed to clone Cartesi-Coprocessor repository.");
-            return;
+        tokio::time::sleep(time::Duration::from_secs(1)).await;
+    }
+}
+
+/// @notice Stop and remove the container backing a service, ignoring the case
+/// where it was never created.
+/// @param docker Connected Docker Engine API client
+/// @param service The compose service whose container should be removed
+async fn stop_and_remove(docker: &Docker, service: &Service) -> Result<(), bollard::errors::Error> {
+    let name = container_name(service);
+
+    let _ = docker
+        .stop_container(&name, Some(StopContainerOptions { t: 10 }))
+        .await;
+
+    match docker
+        .remove_container(
+            &name,
+            Some(RemoveContainerOptions {
+                v: true,
+                force: true,
+                ..Default::default()
+            }),
+        )
+        .await
+    {
+        Ok(()) => Ok(()),
+        // Never created (or already removed); nothing to tear down.
+        Err(bollard::errors::Error::DockerResponseServerError {
+            status_code: 404, ..
+        }) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// @notice Poll every container until it reports a healthy/running state, or
+/// return the name of the first service that never became healthy.
+/// @param docker Connected Docker Engine API client
+async fn wait_for_healthy(docker: &Docker) -> Result<(), String> {
+    for service in SERVICES {
+        let name = container_name(service);
+        let mut healthy = false;
+
+        for _ in 0..60 {
+            let inspect = docker.inspect_container(&name, None).await;
+            if let Ok(details) = inspect {
+                if let Some(state) = details.state {
+                    // A configured healthcheck wins; otherwise a running
+                    // container is treated as up.
+                    if let Some(health) = state.health.as_ref().and_then(|h| h.status) {
+                        if health == HealthStatusEnum::HEALTHY {
+                            healthy = true;
+                            break;
+                        }
+                    } else if state.status == Some(ContainerStateStatusEnum::RUNNING) {
+                        healthy = true;
+                        break;
+                    }
+                }
+            }
+            tokio::time::sleep(time::Duration::from_secs(2)).await;
+        }
+
+        if !healthy {
+            return Err(service.name.to_string());
         }
     }
+    Ok(())
 }
 
-/// @notice Function to build containers for the coprocessor
+/// @notice Function to build containers for the coprocessor. Returns `false`
+/// (after printing a diagnostic) when any service fails to build, so the caller
+/// can abort before starting containers from stale or missing images.
+/// @param docker Connected Docker Engine API client
 /// @param path The path to the local coprocessor repository
-fn build_container(path: String) {
+async fn build_container(docker: &Docker, path: &str) -> bool {
     let spinner = get_spinner();
     spinner.set_message("Building devnet containers...");
 
-    let pull_status = Command::new("docker")
-        .arg("compose")
-        .arg("-f")
-        .arg("docker-compose-devnet.yaml")
-        .arg("build")
-        .current_dir(path)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .expect("Failed to execute build container command")
-        .wait_with_output()
-        .expect("Failed to complete build container command");
+    for service in SERVICES {
+        let Some(context) = service.build_context else {
+            continue;
+        };
 
-    if pull_status.status.success() {
-        spinner.finish_and_clear();
-        println!("✅ {}", "Successfully built Devnet containers.".green());
-    } else {
-        spinner.finish_and_clear();
-        eprintln!("❌ Failed to build containers.");
-        let stderr = String::from_utf8_lossy(&pull_status.stderr);
-        println!("{} {}", "DOCKER::RESPONSE::".red(), stderr.red());
+        let tar = match tar_build_context(&PathBuf::from(path).join(context)) {
+            Ok(tar) => tar,
+            Err(e) => {
+                spinner.finish_and_clear();
+                eprintln!("❌ Failed to package build context for {}.", service.name);
+                println!("{} {}", "DOCKER::RESPONSE::".red(), format!("{:?}", e).red());
+                return false;
+            }
+        };
+
+        let options = BuildImageOptions {
+            dockerfile: service.dockerfile,
+            t: service.image,
+            rm: true,
+            ..Default::default()
+        };
+
+        let mut stream = docker.build_image(options, None, Some(tar.into()));
+        while let Some(event) = stream.next().await {
+            match event {
+                // The Engine API already hands us structured JSON events; we
+                // forward the interesting fields rather than scraping text.
+                Ok(info) => {
+                    if let Some(stream) = info.stream {
+                        spinner.set_message(stream.trim().to_string());
+                    }
+                    if let Some(error) = info.error {
+                        spinner.finish_and_clear();
+                        eprintln!("❌ Failed to build container {}.", service.name);
+                        println!("{} {}", "DOCKER::RESPONSE::".red(), error.red());
+                        return false;
+                    }
+                }
+                Err(e) => {
+                    spinner.finish_and_clear();
+                    eprintln!("❌ Failed to build container {}.", service.name);
+                    println!("{} {}", "DOCKER::RESPONSE::".red(), format!("{:?}", e).red());
+                    return false;
+                }
+            }
+        }
     }
+
+    spinner.finish_and_clear();
+    println!("✅ {}", "Successfully built Devnet containers.".green());
+    true
 }
 
-/// @notice Function to pull updates to the coprocessor containers
-/// @param path The path to the local coprocessor repository
-fn pull_container(path: String) {
+/// @notice Function to pull updates to the coprocessor containers. Returns
+/// `false` (after printing a diagnostic) when a pull fails, so the caller can
+/// abort before starting containers from missing images.
+/// @param docker Connected Docker Engine API client
+/// @param _path The path to the local coprocessor repository (kept for symmetry)
+async fn pull_container(docker: &Docker, _path: &str) -> bool {
     let spinner = get_spinner();
     spinner.set_message("Pulling changes to devnet containers...");
 
-    let pull_status = Command::new("docker")
-        .arg("compose")
-        .arg("-f")
-        .arg("docker-compose-devnet.yaml")
-        .arg("pull")
-        .current_dir(path)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .expect("Failed to pull changes to dev container")
-        .wait_with_output()
-        .expect("Failed to complete pull changes command");
+    for service in SERVICES {
+        // Services built locally do not need to be pulled from a registry.
+        if service.build_context.is_some() {
+            continue;
+        }
 
-    if pull_status.status.success() {
-        spinner.finish_and_clear();
-        println!(
-            "✅ {}",
-            "Successfully pulled changes to Devnet containers.".green()
-        );
-    } else {
-        spinner.finish_and_clear();
-        eprintln!("❌ Failed to pull changes to containers.");
-        let stderr = String::from_utf8_lossy(&pull_status.stderr);
-        println!("{} {}", "DOCKER::RESPONSE::".red(), stderr.red());
+        let options = CreateImageOptions {
+            from_image: service.image,
+            ..Default::default()
+        };
+
+        let mut stream = docker.create_image(Some(options), None, None);
+        while let Some(event) = stream.next().await {
+            match event {
+                Ok(info) => {
+                    if let Some(status) = info.status {
+                        spinner.set_message(status);
+                    }
+                }
+                Err(e) => {
+                    spinner.finish_and_clear();
+                    eprintln!("❌ Failed to pull changes to container {}.", service.name);
+                    println!("{} {}", "DOCKER::RESPONSE::".red(), format!("{:?}", e).red());
+                    return false;
+                }
+            }
+        }
     }
+
+    spinner.finish_and_clear();
+    println!(
+        "✅ {}",
+        "Successfully pulled changes to Devnet containers.".green()
+    );
+    true
+}
+
+/// @notice Package a build context directory into an uncompressed tar archive
+/// as expected by the Engine API's image-build endpoint.
+/// @param context Absolute path to the build context
+fn tar_build_context(context: &PathBuf) -> std::io::Result<Vec<u8>> {
+    let mut builder = tar::Builder::new(Vec::new());
+    builder.append_dir_all(".", context)?;
+    builder.into_inner()
 }
@@ -0,0 +1,71 @@
+use colored::Colorize;
+use fs2::FileExt;
+use std::env;
+use std::fs::{File, OpenOptions};
+use std::path::PathBuf;
+
+/// @notice An advisory file-lock held for the lifetime of a command that mutates
+/// shared global state (the `~/.cartesi-coprocessor-repo` clone, the docker
+/// compose project, the Web3.Storage session). Acquired on entry and released
+/// on drop, the same way concurrent cargo installs coordinate, so two CLI
+/// invocations cannot corrupt each other.
+pub struct RepoLock {
+    _file: Option<File>,
+}
+
+impl RepoLock {
+    /// @notice Environment flag set by a parent invocation that already holds the
+    /// lock, so child invocations it spawns (e.g. the parallel publish workers)
+    /// inherit the hold instead of failing fast against it.
+    pub const INHERIT_ENV: &'static str = "COPROCESSOR_LOCK_HELD";
+
+    /// @notice Acquire the advisory lock, failing fast with a clear message when
+    /// it is already held by another invocation.
+    pub fn acquire() -> Result<RepoLock, String> {
+        // A parent invocation already holds the lock and is coordinating this
+        // one; take a no-op guard rather than blocking on ourselves.
+        if env::var_os(Self::INHERIT_ENV).is_some() {
+            return Ok(RepoLock { _file: None });
+        }
+
+        let path = lock_path();
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&path)
+            .map_err(|e| format!("Failed to open lock file {:?}: {}", path, e))?;
+
+        match file.try_lock_exclusive() {
+            Ok(()) => Ok(RepoLock { _file: Some(file) }),
+            Err(_) => Err(format!(
+                "{} another coprocessor command is already running (lock held at {:?})",
+                "❌".red(),
+                path
+            )),
+        }
+    }
+}
+
+impl Drop for RepoLock {
+    fn drop(&mut self) {
+        // fs2 releases the advisory lock when the file handle closes, but unlock
+        // explicitly so the intent is obvious. Inherited (no-op) guards own no
+        // handle and have nothing to release.
+        if let Some(file) = &self._file {
+            let _ = FileExt::unlock(file);
+        }
+    }
+}
+
+/// @notice Path to the advisory lock file, co-located with the devnet clone so
+/// it guards the same shared directory.
+fn lock_path() -> PathBuf {
+    let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(home)
+        .join(".cartesi-coprocessor-repo")
+        .join(".cli.lock")
+}
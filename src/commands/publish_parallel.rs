@@ -0,0 +1,143 @@
+use crate::commands::config::{CoprocessorConfig, ProgramConfig};
+use crate::commands::lock::RepoLock;
+use colored::Colorize;
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+use std::thread;
+
+/// @notice Outcome of publishing a single program in parallel mode.
+struct ProgramResult {
+    name: String,
+    success: bool,
+    message: String,
+}
+
+/// @notice Build and upload every program listed in the project config at once,
+/// each in its own task with isolated working state, aggregating per-program
+/// success/failure into one final report.
+/// @param network Resolved target network
+/// @param environment Solver environment (dev/test/prod)
+/// @param email Web3.Storage email, if supplied
+/// @param build Whether each program is built before uploading
+pub fn publish_all(network: String, environment: String, email: Option<String>, build: bool) {
+    // The advisory lock is held for the whole parallel run so a second
+    // invocation fails fast rather than racing on shared global state.
+    let _lock = match RepoLock::acquire() {
+        Ok(lock) => lock,
+        Err(e) => {
+            eprintln!("{}", e);
+            return;
+        }
+    };
+
+    let cfg = CoprocessorConfig::load();
+    if cfg.programs.is_empty() {
+        eprintln!(
+            "{}",
+            "❌ No programs listed in coprocessor.toml for parallel publishing".red()
+        );
+        return;
+    }
+
+    let exe = match env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!("{} {}", "❌ Could not locate the coprocessor binary:".red(), e);
+            return;
+        }
+    };
+
+    // The workers re-invoke `publish` as child processes. We already hold the
+    // lock for the whole run, so tell them to inherit it instead of failing
+    // fast; set before spawning any worker and cleared once they have joined.
+    env::set_var(RepoLock::INHERIT_ENV, "1");
+
+    let default_solver = cfg.solver_url.clone();
+    let handles: Vec<_> = cfg
+        .programs
+        .into_iter()
+        .map(|program| {
+            let exe = exe.clone();
+            let network = network.clone();
+            let environment = environment.clone();
+            let email = email.clone();
+            let default_solver = default_solver.clone();
+            thread::spawn(move || {
+                publish_one(&exe, program, network, environment, email, build, default_solver)
+            })
+        })
+        .collect();
+
+    let results: Vec<ProgramResult> = handles
+        .into_iter()
+        .filter_map(|h| h.join().ok())
+        .collect();
+
+    env::remove_var(RepoLock::INHERIT_ENV);
+
+    // Aggregated report.
+    println!("\n{}", "Parallel publish report:".green());
+    let mut failures = 0;
+    for result in &results {
+        if result.success {
+            println!("  ✅ {} — {}", result.name.green(), result.message);
+        } else {
+            failures += 1;
+            println!("  ❌ {} — {}", result.name.red(), result.message);
+        }
+    }
+    if failures > 0 {
+        eprintln!("{} {}/{} program(s) failed", "❌".red(), failures, results.len());
+    }
+}
+
+/// @notice Publish a single program as a child `publish` invocation rooted at the
+/// program's own directory, so concurrent workers never share a working
+/// directory or step on each other's build artifacts, and the real exit status
+/// drives the per-program report.
+fn publish_one(
+    exe: &PathBuf,
+    program: ProgramConfig,
+    network: String,
+    environment: String,
+    email: Option<String>,
+    build: bool,
+    default_solver: Option<String>,
+) -> ProgramResult {
+    let solver_url = program.solver_url.clone().or(default_solver);
+
+    let mut cmd = Command::new(exe);
+    cmd.arg("publish")
+        .arg("--network")
+        .arg(&network)
+        .arg("--environment")
+        .arg(&environment)
+        .arg("--build")
+        .arg(if build { "true" } else { "false" })
+        .current_dir(&program.path);
+    if let Some(email) = &email {
+        cmd.arg("--email").arg(email);
+    }
+    if let Some(solver_url) = &solver_url {
+        cmd.arg("--solver-url").arg(solver_url);
+    }
+
+    match cmd.status() {
+        Ok(status) if status.success() => ProgramResult {
+            name: program.name,
+            success: true,
+            message: "published".to_string(),
+        },
+        Ok(status) => ProgramResult {
+            name: program.name,
+            success: false,
+            message: format!("publish exited with {}", status),
+        },
+        Err(e) => ProgramResult {
+            name: program.name,
+            success: false,
+            message: format!("could not launch publish in {}: {}", program.path, e),
+        },
+    }
+}
@@ -0,0 +1,109 @@
+use crate::commands::publish::build_cartesi_machine_and_generate_car;
+use crate::helpers::helpers::get_spinner;
+use colored::Colorize;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// @notice Directories and file names whose changes must never trigger a
+/// rebuild, otherwise the generated artifacts would feed back into the watcher
+/// and loop forever.
+const IGNORED: &[&str] = &[".car", "target", ".git", "node_modules"];
+
+/// @notice Window over which a burst of filesystem events is collapsed into a
+/// single rebuild, so a save that touches many files only rebuilds once.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// @notice Function to watch the dapp source directory and re-run the Cartesi
+/// machine build whenever a relevant source file changes.
+/// @param path The dapp source directory to monitor (defaults to the current directory)
+pub fn watch(path: Option<String>) {
+    let root = path.unwrap_or_else(|| ".".to_string());
+
+    // The watcher runs on its own thread and feeds events back through a
+    // channel to the rebuild loop below.
+    let (tx, rx) = channel();
+    let mut watcher = match notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    }) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            eprintln!("{} {}", "❌ Failed to start file watcher:".red(), e);
+            return;
+        }
+    };
+
+    if let Err(e) = watcher.watch(Path::new(&root), RecursiveMode::Recursive) {
+        eprintln!("{} {}", "❌ Failed to watch directory:".red(), e);
+        return;
+    }
+
+    println!(
+        "👀 {} {}",
+        "Watching for changes in".green(),
+        root.green()
+    );
+
+    // Do an initial build so the artifacts are fresh before the first edit.
+    rebuild();
+
+    // Debounce loop: once a relevant event arrives we keep draining the channel
+    // until it has been quiet for `DEBOUNCE`, then rebuild exactly once.
+    loop {
+        match rx.recv() {
+            Ok(Ok(event)) if is_relevant(&event) => {
+                let mut deadline = Instant::now() + DEBOUNCE;
+                loop {
+                    match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                        Ok(Ok(event)) if is_relevant(&event) => {
+                            deadline = Instant::now() + DEBOUNCE;
+                        }
+                        Ok(_) => continue,
+                        Err(RecvTimeoutError::Timeout) => break,
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                }
+                rebuild();
+            }
+            Ok(_) => continue,
+            Err(_) => return,
+        }
+    }
+}
+
+/// @notice Whether a filesystem event should trigger a rebuild: only
+/// create/modify/remove events on non-ignored paths count.
+fn is_relevant(event: &notify::Event) -> bool {
+    if !matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        return false;
+    }
+
+    !event.paths.iter().any(|p| {
+        p.components().any(|c| {
+            IGNORED
+                .iter()
+                .any(|ignored| c.as_os_str().to_string_lossy().ends_with(ignored))
+        })
+    })
+}
+
+/// @notice Re-run the Cartesi machine build and print a concise per-cycle
+/// summary of how long it took.
+fn rebuild() {
+    let spinner = get_spinner();
+    spinner.set_message("Rebuilding Cartesi machine...");
+    let started = Instant::now();
+
+    let _ = build_cartesi_machine_and_generate_car();
+
+    spinner.finish_and_clear();
+    println!(
+        "🔁 {} ({:.1}s)",
+        "Rebuild complete.".green(),
+        started.elapsed().as_secs_f32()
+    );
+}
@@ -3,6 +3,14 @@ mod helpers;
 use crate::commands::create::create;
 use crate::commands::devnet::{reset_devnet, start_devnet, stop_devnet, update_devnet};
 use crate::commands::publish::build_cartesi_machine_and_generate_car;
+use crate::commands::config::{config, CoprocessorConfig};
+use crate::commands::dashboard::dashboard;
+use crate::commands::dbctx::{
+    history, now_timestamp, reconcile_recorded_publishes, record_and_notify, Run, RunKind,
+};
+use crate::commands::lock::RepoLock;
+use crate::commands::publish_parallel::publish_all;
+use crate::commands::watch::watch;
 use crate::helpers::helpers::{check_dependencies_installed, check_network_and_confirm_status};
 use clap::{Parser, Subcommand};
 use helpers::helpers::{
@@ -37,9 +45,9 @@ enum Commands {
         #[arg(
             short,
             long,
-            help = "Environment where your program will be deployed to, e.g. Devnet, Mainnet or Testnet"
+            help = "Environment where your program will be deployed to, e.g. Devnet, Mainnet or Testnet (optional when a default_network is set in coprocessor.toml)"
         )]
-        network: String,
+        network: Option<String>,
 
         #[arg(
             long,
@@ -67,6 +75,12 @@ enum Commands {
             help = "Optional custom solver URL to override the default solver URL"
         )]
         solver_url: Option<String>,
+
+        #[arg(
+            long,
+            help = "Build and upload every program listed in coprocessor.toml concurrently"
+        )]
+        parallel: bool,
     },
     #[command(
         about = "Bootstrap a new directory for your program",
@@ -107,9 +121,9 @@ enum Commands {
         #[arg(
             short,
             long,
-            help = "Environment where your program will be deployed to, e.g. Devnet, Mainnet or Testnet"
+            help = "Environment where your program will be deployed to, e.g. Devnet, Mainnet or Testnet (optional when a default_network is set in coprocessor.toml)"
         )]
-        network: String,
+        network: Option<String>,
 
         #[arg(short, long, help = "Private key for deploying to selected network")]
         private_key: Option<String>,
@@ -127,6 +141,19 @@ enum Commands {
         constructor_args: Option<Vec<String>>,
     },
 
+    #[command(
+        about = "Watch the dapp source directory and rebuild the Cartesi machine on changes",
+        long_about = "Watch the dapp source directory and automatically re-run the Cartesi machine build whenever a relevant source file changes, giving a fast inner development loop"
+    )]
+    Watch {
+        #[arg(
+            short,
+            long,
+            help = "Source directory to watch (defaults to the current directory)"
+        )]
+        path: Option<String>,
+    },
+
     #[command(about = "Pull the latest changes from the release branch for devnet")]
     UpdateDevnet,
 
@@ -138,6 +165,36 @@ enum Commands {
         long_about = "Displays the machine Hash and also co-processor address on different networks"
     )]
     AddressBook,
+
+    #[command(
+        about = "Initialize and print the resolved coprocessor.toml configuration",
+        long_about = "Initialize a coprocessor.toml in the project root and/or print the resolved configuration (user-level fallback and project file merged together)"
+    )]
+    Config {
+        #[arg(long, help = "Write a starter coprocessor.toml to the project root if absent")]
+        init: bool,
+    },
+
+    #[command(
+        about = "List past build, publish and deploy runs recorded locally",
+        long_about = "List and filter the local SQLite history of Build, Publish and Deploy runs recorded by the CLI"
+    )]
+    History {
+        #[arg(long, help = "Filter by run kind: build, publish or deploy")]
+        kind: Option<String>,
+
+        #[arg(short, long, help = "Filter by target network")]
+        network: Option<String>,
+    },
+
+    #[command(
+        about = "Open an interactive TUI dashboard for devnet and solver status",
+        long_about = "Open a terminal UI showing live devnet container health, streaming logs, the current machine hash and CAR CID, and solver status, with keybindings to start/stop/reset the devnet and trigger a rebuild"
+    )]
+    Dashboard {
+        #[arg(long, help = "Solver URL whose /health endpoint is polled for reachability")]
+        solver_url: Option<String>,
+    },
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -153,16 +210,40 @@ fn main() -> Result<(), Box<dyn Error>> {
                 Ok(())
             }
             Commands::StartDevnet => {
+                let _lock = match RepoLock::acquire() {
+                    Ok(lock) => lock,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return Ok(());
+                    }
+                };
                 start_devnet();
                 Ok(())
             }
             Commands::StopDevnet => {
+                let _lock = match RepoLock::acquire() {
+                    Ok(lock) => lock,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return Ok(());
+                    }
+                };
                 stop_devnet();
                 Ok(())
             }
 
             Commands::Build => {
-                build_cartesi_machine_and_generate_car();
+                let artifacts = build_cartesi_machine_and_generate_car();
+                record_and_notify(Run {
+                    kind: RunKind::Build,
+                    machine_hash: artifacts.as_ref().map(|a| a.machine_hash.clone()),
+                    car_cid: artifacts.as_ref().map(|a| a.car_cid.clone()),
+                    network: None,
+                    solver_url: None,
+                    contract_address: None,
+                    timestamp: now_timestamp(),
+                    success: artifacts.is_some(),
+                });
                 Ok(())
             }
 
@@ -173,20 +254,63 @@ fn main() -> Result<(), Box<dyn Error>> {
                 environment,
                 check_status,
                 solver_url,
+                parallel,
             } => {
                 let check_status = decode_string_to_bool(check_status, "check_status");
                 let build = decode_string_to_bool(build, "build");
 
+                let cfg = CoprocessorConfig::load();
+                let network = match cfg.resolve_network(network) {
+                    Some(network) => network,
+                    None => {
+                        eprintln!("❌ No network specified and no default_network set in coprocessor.toml");
+                        return Ok(());
+                    }
+                };
+                let profile = cfg.profile(&network);
+                let environment = profile
+                    .and_then(|p| p.environment.clone())
+                    .unwrap_or(environment);
+                let solver_url = solver_url
+                    .or_else(|| profile.and_then(|p| p.solver_url.clone()))
+                    .or_else(|| cfg.solver_url.clone());
+
                 if check_status != Err(()) && build != Err(()) {
-                    check_registration_environment(
+                    if parallel {
+                        publish_all(network, environment, email, build.unwrap());
+                        return Ok(());
+                    }
+
+                    // Guard the shared repo/compose/Web3.Storage state so a
+                    // second invocation fails fast instead of corrupting it.
+                    let _lock = match RepoLock::acquire() {
+                        Ok(lock) => lock,
+                        Err(e) => {
+                            eprintln!("{}", e);
+                            return Ok(());
+                        }
+                    };
+
+                    let outcome = check_registration_environment(
                         network.clone(),
                         environment.clone(),
                         email,
                         build.unwrap(),
-                        solver_url,
+                        solver_url.clone(),
                     );
+                    record_and_notify(Run {
+                        kind: RunKind::Publish,
+                        machine_hash: outcome.as_ref().map(|a| a.machine_hash.clone()),
+                        car_cid: outcome.as_ref().map(|a| a.car_cid.clone()),
+                        network: Some(network.clone()),
+                        solver_url: solver_url.clone(),
+                        contract_address: None,
+                        timestamp: now_timestamp(),
+                        success: outcome.is_some(),
+                    });
                     if check_status.unwrap() {
-                        check_network_and_confirm_status(network, environment);
+                        check_network_and_confirm_status(network.clone(), environment);
+                        reconcile_recorded_publishes(&network, solver_url.as_deref());
                     }
                 }
 
@@ -200,21 +324,66 @@ fn main() -> Result<(), Box<dyn Error>> {
                 rpc,
                 constructor_args,
             } => {
-                check_deployment_environment(
-                    network,
+                let cfg = CoprocessorConfig::load();
+                let network = match cfg.resolve_network(network) {
+                    Some(network) => network,
+                    None => {
+                        eprintln!("❌ No network specified and no default_network set in coprocessor.toml");
+                        return Ok(());
+                    }
+                };
+                let profile = cfg.profile(&network);
+                let private_key = private_key
+                    .or_else(|| profile.and_then(|p| p.private_key.clone()))
+                    .or_else(|| cfg.private_key.clone());
+                let rpc = rpc
+                    .or_else(|| profile.and_then(|p| p.rpc.clone()))
+                    .or_else(|| cfg.rpc.clone());
+
+                let contract_address = check_deployment_environment(
+                    network.clone(),
                     private_key,
                     rpc,
                     constructor_args,
                     contract_name,
                 );
+                record_and_notify(Run {
+                    kind: RunKind::Deploy,
+                    machine_hash: None,
+                    car_cid: None,
+                    network: Some(network),
+                    solver_url: None,
+                    contract_address: contract_address.clone(),
+                    timestamp: now_timestamp(),
+                    success: contract_address.is_some(),
+                });
+                Ok(())
+            }
+
+            Commands::Watch { path } => {
+                watch(path);
                 Ok(())
             }
 
             Commands::UpdateDevnet => {
+                let _lock = match RepoLock::acquire() {
+                    Ok(lock) => lock,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return Ok(());
+                    }
+                };
                 update_devnet();
                 Ok(())
             }
             Commands::ResetDevnet => {
+                let _lock = match RepoLock::acquire() {
+                    Ok(lock) => lock,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return Ok(());
+                    }
+                };
                 reset_devnet();
                 Ok(())
             }
@@ -223,6 +392,21 @@ fn main() -> Result<(), Box<dyn Error>> {
                 address_book();
                 Ok(())
             }
+
+            Commands::Config { init } => {
+                config(init);
+                Ok(())
+            }
+
+            Commands::History { kind, network } => {
+                history(kind, network);
+                Ok(())
+            }
+
+            Commands::Dashboard { solver_url } => {
+                dashboard(solver_url);
+                Ok(())
+            }
         },
     }
 }